@@ -0,0 +1,320 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Localization, LocalizationUnit, Token};
+
+impl Localization {
+    /// Generate Rust source defining one accessor function per localisation key, intended to
+    /// be called from a consumer's `build.rs` so that referencing a missing key becomes a
+    /// compile error instead of a runtime `None`.
+    ///
+    /// Keys are taken from the *first* entry of `localizations` (the base language) so that
+    /// a single source of truth drives the generated bindings. Keys are turned into valid
+    /// Rust identifiers with [`normalize_identifier`]; if two distinct keys normalize to the
+    /// same identifier, later ones are disambiguated with a numeric suffix (`_2`, `_3`, ...)
+    /// rather than silently dropped. An exact duplicate key (the same key appearing twice) is
+    /// still only bound once. A key whose value contains `$VARIABLE$` placeholders (see
+    /// [`LocalizationUnit::tokens`]) becomes a function taking one `&str` parameter per
+    /// distinct variable name and returning a formatted `String`; a key with no placeholders
+    /// becomes a function returning `&'static str`.
+    pub fn generate_bindings(localizations: &[Localization]) -> String {
+        let Some(base) = localizations.first() else {
+            return String::new();
+        };
+
+        let mut seen_keys = HashSet::new();
+        let mut seen_names: HashMap<String, usize> = HashMap::new();
+        let mut out = String::new();
+
+        for unit in &base.units {
+            if !seen_keys.insert(unit.key.clone()) {
+                continue;
+            }
+
+            let base_name = normalize_identifier(&unit.key);
+            let name = match seen_names.get_mut(&base_name) {
+                Some(count) => {
+                    *count += 1;
+                    format!("{base_name}_{count}")
+                }
+                None => {
+                    seen_names.insert(base_name.clone(), 1);
+                    base_name
+                }
+            };
+
+            out.push_str(&generate_accessor(&name, unit));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn generate_accessor(name: &str, unit: &LocalizationUnit) -> String {
+    let tokens = unit.tokens();
+    let variables = variable_params(&tokens);
+
+    if variables.is_empty() {
+        format!("pub fn {name}() -> &'static str {{\n    {:?}\n}}\n", unit.value)
+    } else {
+        let params = variables
+            .iter()
+            .map(|v| format!("{v}: &str"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let format_str = render_format_string(&tokens);
+        format!("pub fn {name}({params}) -> String {{\n    format!({format_str:?})\n}}\n")
+    }
+}
+
+/// The distinct `$VARIABLE$` names referenced by `tokens`, lowercased into valid parameter
+/// names, in first-seen order.
+fn variable_params(tokens: &[Token]) -> Vec<String> {
+    let mut params = Vec::new();
+    for token in tokens {
+        if let Token::Variable(variable) = token {
+            let param = normalize_variable_param(variable);
+            if !params.contains(&param) {
+                params.push(param);
+            }
+        }
+        if let Token::Color { body, .. } = token {
+            for param in variable_params(body) {
+                if !params.contains(&param) {
+                    params.push(param);
+                }
+            }
+        }
+    }
+    params
+}
+
+/// Render `tokens` back into a `format!`-style string, with `$VARIABLE$` placeholders
+/// replaced by `{variable}` captures that match the parameter names from [`variable_params`].
+fn render_format_string(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    for token in tokens {
+        match token {
+            Token::Literal(text) => out.push_str(&escape_format_braces(text)),
+            Token::Variable(variable) => {
+                // A raw identifier like `r#match` can't be written as `{r#match}` in a format
+                // string; `format!` captures keyword-named variables by their bare name.
+                let param = normalize_variable_param(variable);
+                out.push('{');
+                out.push_str(param.strip_prefix("r#").unwrap_or(&param));
+                out.push('}');
+            }
+            Token::Newline => out.push_str("\\n"),
+            Token::Scope(scope) => {
+                out.push('[');
+                out.push_str(&escape_format_braces(scope));
+                out.push(']');
+            }
+            Token::Icon(icon) => {
+                out.push('£');
+                out.push_str(&escape_format_braces(icon));
+            }
+            Token::Color { code, body } => {
+                out.push('§');
+                out.push(*code);
+                out.push_str(&render_format_string(body));
+                out.push_str("§!");
+            }
+        }
+    }
+    out
+}
+
+fn escape_format_braces(text: &str) -> String {
+    text.replace('{', "{{").replace('}', "}}")
+}
+
+/// Rust keywords that cannot be used as a raw identifier (`r#...`) and so need a different
+/// escape.
+const UNRAWABLE_KEYWORDS: &[&str] = &["crate", "self", "super", "Self", "_"];
+
+/// Every other strict or reserved Rust keyword, which can be escaped with `r#`.
+const RAW_ESCAPABLE_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "else", "enum", "extern", "false", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized",
+    "virtual", "yield", "try",
+];
+
+/// Turn a `$VARIABLE$` name into a valid, lowercased Rust parameter name. Lowercasing happens
+/// *before* [`normalize_identifier`]'s keyword/digit checks, so a variable like `$MATCH$`
+/// (which only collides with the `match` keyword once lowercased) still gets escaped.
+fn normalize_variable_param(variable: &str) -> String {
+    normalize_identifier(&variable.to_lowercase())
+}
+
+/// Turn a localisation key (which, per [`crate::Localization::try_parse`], can contain any
+/// character) into a valid Rust identifier: non-identifier characters become `_`, a leading
+/// digit gets an `_` prefix, and a name that collides with a Rust keyword is escaped.
+fn normalize_identifier(key: &str) -> String {
+    let mut identifier: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+
+    if UNRAWABLE_KEYWORDS.contains(&identifier.as_str()) {
+        identifier.push('_');
+    } else if RAW_ESCAPABLE_KEYWORDS.contains(&identifier.as_str()) {
+        identifier = format!("r#{identifier}");
+    }
+
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_a_plain_function_for_keys_without_variables() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![LocalizationUnit {
+                key: "wuw_GER_news.1.t".to_string(),
+                version: Some(0),
+                value: "Reunification of Germany".to_string(),
+            }],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert_eq!(
+            bindings,
+            "pub fn wuw_GER_news_1_t() -> &'static str {\n    \"Reunification of Germany\"\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn generates_a_formatting_function_for_keys_with_variables() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![LocalizationUnit {
+                key: "wuw_GER_news.1.d".to_string(),
+                version: Some(0),
+                value: "Hail $LEADER$!".to_string(),
+            }],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert_eq!(
+            bindings,
+            "pub fn wuw_GER_news_1_d(leader: &str) -> String {\n    format!(\"Hail {leader}!\")\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn skips_duplicate_keys() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![
+                LocalizationUnit {
+                    key: "a".to_string(),
+                    version: None,
+                    value: "first".to_string(),
+                },
+                LocalizationUnit {
+                    key: "a".to_string(),
+                    version: None,
+                    value: "second".to_string(),
+                },
+            ],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert_eq!(bindings.matches("pub fn a").count(), 1);
+    }
+
+    #[test]
+    fn normalizes_keys_that_start_with_a_digit() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![LocalizationUnit {
+                key: "1.t".to_string(),
+                version: None,
+                value: "value".to_string(),
+            }],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert!(bindings.contains("pub fn _1_t()"));
+    }
+
+    #[test]
+    fn escapes_keys_that_are_rust_keywords() {
+        let localizations = vec![
+            Localization {
+                lang: "english".to_string(),
+                units: vec![
+                    LocalizationUnit {
+                        key: "match".to_string(),
+                        version: None,
+                        value: "value".to_string(),
+                    },
+                    LocalizationUnit {
+                        key: "self".to_string(),
+                        version: None,
+                        value: "value".to_string(),
+                    },
+                ],
+            },
+        ];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert!(bindings.contains("pub fn r#match()"));
+        assert!(bindings.contains("pub fn self_()"));
+    }
+
+    #[test]
+    fn escapes_variables_that_are_rust_keywords_once_lowercased() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![LocalizationUnit {
+                key: "a".to_string(),
+                version: None,
+                value: "Hail $MATCH$!".to_string(),
+            }],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert!(bindings.contains("pub fn a(r#match: &str)"));
+        assert!(bindings.contains("format!(\"Hail {match}!\")"));
+    }
+
+    #[test]
+    fn disambiguates_keys_that_normalize_to_the_same_identifier() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![
+                LocalizationUnit {
+                    key: "a.b".to_string(),
+                    version: None,
+                    value: "first".to_string(),
+                },
+                LocalizationUnit {
+                    key: "a_b".to_string(),
+                    version: None,
+                    value: "second".to_string(),
+                },
+            ],
+        }];
+
+        let bindings = Localization::generate_bindings(&localizations);
+
+        assert!(bindings.contains("pub fn a_b()"));
+        assert!(bindings.contains("pub fn a_b_2()"));
+    }
+}
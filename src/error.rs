@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// An error encountered while parsing Hearts of Iron IV localisation data.
+///
+/// Every variant carries the 1-based line number and the offending line's raw text so that
+/// callers can point a localizer (or a diagnostics UI) at the exact spot in the source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A unit line (`key:version "value"`) was found before any `l_<language>` header.
+    UnitBeforeLanguageHeader { line: usize, text: String },
+    /// A line following a language header didn't match the `key:version "value"` shape.
+    MalformedUnit { line: usize, text: String },
+    /// A unit's value started with an opening quote but never reached a closing one.
+    UnterminatedValue { line: usize, text: String },
+    /// A unit's `:version` suffix was present but couldn't be parsed as an integer.
+    InvalidVersion { line: usize, text: String },
+}
+
+impl ParseError {
+    /// The 1-based line number the error occurred on.
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::UnitBeforeLanguageHeader { line, .. }
+            | ParseError::MalformedUnit { line, .. }
+            | ParseError::UnterminatedValue { line, .. }
+            | ParseError::InvalidVersion { line, .. } => *line,
+        }
+    }
+
+    /// The raw text of the offending line.
+    pub fn text(&self) -> &str {
+        match self {
+            ParseError::UnitBeforeLanguageHeader { text, .. }
+            | ParseError::MalformedUnit { text, .. }
+            | ParseError::UnterminatedValue { text, .. }
+            | ParseError::InvalidVersion { text, .. } => text,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnitBeforeLanguageHeader { line, text } => write!(
+                f,
+                "line {line}: unit appears before any `l_<language>` header: {text:?}"
+            ),
+            ParseError::MalformedUnit { line, text } => write!(
+                f,
+                "line {line}: expected `key:version \"value\"`, found: {text:?}"
+            ),
+            ParseError::UnterminatedValue { line, text } => {
+                write!(f, "line {line}: unterminated quoted value: {text:?}")
+            }
+            ParseError::InvalidVersion { line, text } => {
+                write!(f, "line {line}: version is not a valid integer: {text:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
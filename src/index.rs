@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::{LanguageId, Localization};
+
+struct VersionedValue {
+    value: String,
+    version: i32,
+}
+
+/// A merged lookup index built from one or more parsed [`Localization`] files, keyed by
+/// localisation key and then by the raw Paradox language suffix (e.g. `russian`).
+///
+/// Looking a key up for a language that has no translation falls back through an ordered
+/// chain of other languages set with [`LocalizationIndex::set_fallback_chain`], mirroring how
+/// Paradox games fall back to `english` when a translation is missing.
+#[derive(Default)]
+pub struct LocalizationIndex {
+    entries: HashMap<String, HashMap<String, VersionedValue>>,
+    fallback_chain: Vec<LanguageId>,
+}
+
+impl LocalizationIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index from a sequence of parsed localisation files, merging all of them
+    /// together. If a key is defined more than once for the same language, the entry with
+    /// the higher `:version` replaces the earlier one.
+    pub fn from_files<I>(localizations: I) -> Self
+    where
+        I: IntoIterator<Item = Localization>,
+    {
+        let mut index = Self::new();
+        for localization in localizations {
+            index.merge(localization);
+        }
+        index
+    }
+
+    fn merge(&mut self, localization: Localization) {
+        let lang = localization.lang;
+        for unit in localization.units {
+            let version = unit.version.unwrap_or(0);
+            let per_language = self.entries.entry(unit.key).or_default();
+
+            let should_overwrite = match per_language.get(&lang) {
+                Some(existing) => version >= existing.version,
+                None => true,
+            };
+
+            if should_overwrite {
+                per_language.insert(
+                    lang.clone(),
+                    VersionedValue {
+                        value: unit.value,
+                        version,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Set the ordered chain of languages to fall back to when [`LocalizationIndex::get`]
+    /// can't find a translation for the requested language, e.g. `[en]` so a missing `ru`
+    /// translation falls back to `en`. The *last* language in the chain is treated as the
+    /// base language that [`LocalizationIndex::missing_keys`] compares against.
+    pub fn set_fallback_chain(&mut self, chain: &[LanguageId]) {
+        self.fallback_chain = chain.to_vec();
+    }
+
+    /// Look up `key` for `lang`, falling back through the configured fallback chain (see
+    /// [`LocalizationIndex::set_fallback_chain`]) if `lang` has no translation for it.
+    pub fn get(&self, key: &str, lang: &LanguageId) -> Option<&str> {
+        let per_language = self.entries.get(key)?;
+
+        std::iter::once(lang)
+            .chain(&self.fallback_chain)
+            .find_map(|candidate| per_language.get(candidate.paradox_name()))
+            .map(|versioned| versioned.value.as_str())
+    }
+
+    /// The keys that are translated for the base language (the last entry of the fallback
+    /// chain set with [`LocalizationIndex::set_fallback_chain`]) but not for `lang`.
+    pub fn missing_keys(&self, lang: &LanguageId) -> Vec<&str> {
+        let Some(base) = self.fallback_chain.last() else {
+            return Vec::new();
+        };
+
+        self.entries
+            .iter()
+            .filter(|(_, per_language)| per_language.contains_key(base.paradox_name()))
+            .filter(|(_, per_language)| !per_language.contains_key(lang.paradox_name()))
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LocalizationUnit;
+
+    fn localization(lang: &str, units: Vec<(&str, Option<i32>, &str)>) -> Localization {
+        Localization {
+            lang: lang.to_string(),
+            units: units
+                .into_iter()
+                .map(|(key, version, value)| LocalizationUnit {
+                    key: key.to_string(),
+                    version,
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn resolves_through_the_fallback_chain() {
+        let mut index = LocalizationIndex::from_files(vec![
+            localization("english", vec![("greeting", None, "Hello")]),
+            localization("russian", vec![("farewell", None, "Пока")]),
+        ]);
+        index.set_fallback_chain(&[LanguageId::canonicalize("english").unwrap()]);
+
+        let russian = LanguageId::canonicalize("russian").unwrap();
+        assert_eq!(index.get("greeting", &russian), Some("Hello"));
+        assert_eq!(index.get("farewell", &russian), Some("Пока"));
+        assert_eq!(index.get("missing", &russian), None);
+    }
+
+    #[test]
+    fn higher_version_overrides_lower_version() {
+        let index = LocalizationIndex::from_files(vec![
+            localization("english", vec![("greeting", Some(0), "Hello")]),
+            localization("english", vec![("greeting", Some(1), "Hi")]),
+        ]);
+
+        let english = LanguageId::canonicalize("english").unwrap();
+        assert_eq!(index.get("greeting", &english), Some("Hi"));
+    }
+
+    #[test]
+    fn lower_version_does_not_override_higher_version() {
+        let index = LocalizationIndex::from_files(vec![
+            localization("english", vec![("greeting", Some(1), "Hi")]),
+            localization("english", vec![("greeting", Some(0), "Hello")]),
+        ]);
+
+        let english = LanguageId::canonicalize("english").unwrap();
+        assert_eq!(index.get("greeting", &english), Some("Hi"));
+    }
+
+    #[test]
+    fn reports_missing_keys_relative_to_the_base_language() {
+        let mut index = LocalizationIndex::from_files(vec![
+            localization("english", vec![("greeting", None, "Hello"), ("farewell", None, "Bye")]),
+            localization("russian", vec![("greeting", None, "Привет")]),
+        ]);
+        index.set_fallback_chain(&[LanguageId::canonicalize("english").unwrap()]);
+
+        let russian = LanguageId::canonicalize("russian").unwrap();
+        assert_eq!(index.missing_keys(&russian), vec!["farewell"]);
+    }
+}
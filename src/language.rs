@@ -0,0 +1,185 @@
+use crate::Localization;
+
+/// Paradox's `l_<language>` header names, mapped to their [BCP 47][bcp47] equivalent.
+///
+/// [bcp47]: https://www.rfc-editor.org/rfc/rfc5646
+const KNOWN_LANGUAGES: &[(&str, &str)] = &[
+    ("english", "en"),
+    ("french", "fr"),
+    ("german", "de"),
+    ("polish", "pl"),
+    ("spanish", "es"),
+    ("italian", "it"),
+    ("dutch", "nl"),
+    ("swedish", "sv"),
+    ("czech", "cs"),
+    ("hungarian", "hu"),
+    ("portuguese", "pt"),
+    ("braz_por", "pt-BR"),
+    ("russian", "ru"),
+    ("finnish", "fi"),
+    ("japanese", "ja"),
+    ("korean", "ko"),
+    ("turkish", "tr"),
+    ("simp_chinese", "zh-Hans"),
+];
+
+/// A canonicalized Paradox language, pairing the lowercased `l_<language>` header suffix (e.g.
+/// `braz_por`) with its [BCP 47][bcp47] language identifier (e.g. `pt-BR`).
+///
+/// [bcp47]: https://www.rfc-editor.org/rfc/rfc5646
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageId {
+    bcp47: String,
+    paradox: String,
+}
+
+impl LanguageId {
+    /// Canonicalize a Paradox `l_<language>` header suffix (e.g. `russian`, as found in
+    /// [`Localization::lang`]) into a [`LanguageId`].
+    ///
+    /// Known Paradox language names are mapped through a lookup table. Anything else is
+    /// accepted as-is, as long as it already has the shape of a BCP 47 language tag
+    /// (alphanumeric subtags separated by `-`), with conventional casing applied: the primary
+    /// subtag is lowercased, a 4-letter script subtag is title-cased and a 2-letter region
+    /// subtag is upper-cased. Input that doesn't fit that shape returns `None`.
+    pub fn canonicalize(paradox_name: &str) -> Option<LanguageId> {
+        let paradox = paradox_name.to_lowercase();
+
+        if let Some((_, bcp47)) = KNOWN_LANGUAGES.iter().find(|(name, _)| *name == paradox) {
+            return Some(LanguageId {
+                bcp47: bcp47.to_string(),
+                paradox,
+            });
+        }
+
+        if is_bcp47_subtag_shape(&paradox) {
+            Some(LanguageId {
+                bcp47: canonical_subtag_case(&paradox),
+                paradox,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The canonical BCP 47 language identifier, e.g. `pt-BR`.
+    pub fn code(&self) -> &str {
+        &self.bcp47
+    }
+
+    /// The Paradox `l_<language>` header suffix this id was canonicalized from, lowercased,
+    /// e.g. `braz_por`.
+    pub fn paradox_name(&self) -> &str {
+        &self.paradox
+    }
+
+    /// Round-trip back to the Paradox `l_<language>:` header line this id came from.
+    pub fn paradox_header(&self) -> String {
+        format!("l_{}:", self.paradox)
+    }
+}
+
+fn is_bcp47_subtag_shape(s: &str) -> bool {
+    !s.is_empty()
+        && s.split('-')
+            .all(|subtag| !subtag.is_empty() && subtag.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Apply BCP 47's conventional casing to an already-lowercased tag: the primary language
+/// subtag stays lowercase, a 4-letter script subtag (e.g. `Hant`) is title-cased, and a
+/// 2-letter region subtag (e.g. `PT`) is upper-cased. Anything else (extensions, numeric
+/// region codes, variants) is left lowercase.
+fn canonical_subtag_case(tag: &str) -> String {
+    tag.split('-')
+        .enumerate()
+        .map(|(i, subtag)| match i {
+            0 => subtag.to_string(),
+            _ if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                title_case(subtag)
+            }
+            _ if subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                subtag.to_uppercase()
+            }
+            _ => subtag.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn title_case(subtag: &str) -> String {
+    let mut chars = subtag.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+impl Localization {
+    /// Canonicalize [`Localization::lang`] into a [`LanguageId`]. See
+    /// [`LanguageId::canonicalize`] for the rules used.
+    pub fn language_id(&self) -> Option<LanguageId> {
+        LanguageId::canonicalize(&self.lang)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalizes_known_paradox_language_names() {
+        assert_eq!(LanguageId::canonicalize("english").unwrap().code(), "en");
+        assert_eq!(LanguageId::canonicalize("russian").unwrap().code(), "ru");
+        assert_eq!(LanguageId::canonicalize("braz_por").unwrap().code(), "pt-BR");
+        assert_eq!(
+            LanguageId::canonicalize("simp_chinese").unwrap().code(),
+            "zh-Hans"
+        );
+    }
+
+    #[test]
+    fn canonicalization_is_case_insensitive() {
+        assert_eq!(LanguageId::canonicalize("RUSSIAN").unwrap().code(), "ru");
+    }
+
+    #[test]
+    fn falls_back_to_the_input_tag_when_it_looks_like_bcp47() {
+        let id = LanguageId::canonicalize("pt-PT").unwrap();
+        assert_eq!(id.code(), "pt-PT");
+        assert_eq!(id.paradox_name(), "pt-pt");
+    }
+
+    #[test]
+    fn fallback_title_cases_script_subtags() {
+        let id = LanguageId::canonicalize("zh-hant").unwrap();
+        assert_eq!(id.code(), "zh-Hant");
+    }
+
+    #[test]
+    fn fallback_upper_cases_region_subtags() {
+        let id = LanguageId::canonicalize("es-mx").unwrap();
+        assert_eq!(id.code(), "es-MX");
+    }
+
+    #[test]
+    fn rejects_input_that_cannot_be_a_bcp47_tag() {
+        assert_eq!(LanguageId::canonicalize("not a language!"), None);
+    }
+
+    #[test]
+    fn round_trips_back_to_the_paradox_header() {
+        let id = LanguageId::canonicalize("braz_por").unwrap();
+        assert_eq!(id.paradox_header(), "l_braz_por:");
+    }
+
+    #[test]
+    fn localization_exposes_its_language_id() {
+        let localization = Localization {
+            lang: "english".to_string(),
+            units: Vec::new(),
+        };
+
+        assert_eq!(localization.language_id().unwrap().code(), "en");
+    }
+}
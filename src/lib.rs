@@ -1,6 +1,17 @@
-use std::collections::HashMap;
+mod codegen;
+mod error;
+mod index;
+mod language;
+mod token;
+mod writer;
 
-#[derive(Debug, PartialEq)]
+pub use error::ParseError;
+pub use index::LocalizationIndex;
+pub use language::LanguageId;
+pub use token::Token;
+pub use writer::{Line, LocalizationDocument};
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct LocalizationUnit {
     pub key: String,
     pub version: Option<i32>,
@@ -34,70 +45,133 @@ impl Localization {
     /// input string. The `units` field of each `Localization` object contains a `LocalizationUnit`
     /// object for each unit entry associated with the language entry.
     pub fn parse(content: impl AsRef<str>) -> Vec<Self> {
-        let content = content.as_ref();
-        let lines = content.lines().collect::<Vec<_>>();
-
-        // every lang entry starts with l_
-        let lang_entries: Vec<usize> = lines
-            .iter()
-            .enumerate()
-            .filter_map(|(i, line)| {
-                if line.starts_with("l_") {
-                    Some(i)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        Self::try_parse(content).unwrap()
+    }
 
-        let mut units_for_entry: HashMap<&str, Vec<&str>> = HashMap::new();
+    /// Parse a string containing Hearts of Iron IV localisation data, without panicking on
+    /// malformed input.
+    ///
+    /// This walks the input a single time, line by line, and keeps going past any line it
+    /// can't make sense of so that every problem in the file is reported together instead of
+    /// only the first one. On success the result is identical to [`Localization::parse`]; on
+    /// failure the errors are returned in the order the offending lines appear in `content`,
+    /// each one carrying its 1-based line number and the raw line text. See [`ParseError`] for
+    /// the kinds of problems that are detected.
+    pub fn try_parse(content: impl AsRef<str>) -> Result<Vec<Self>, Vec<ParseError>> {
+        let content = content.as_ref();
+        let regexes = UnitLineRegexes::new();
 
-        for (i, lang_entry_idx) in lang_entries.iter().enumerate() {
-            let next_entry_idx = if i == lang_entries.len() - 1 {
-                lines.len()
-            } else {
-                lang_entries[i + 1]
-            };
+        let mut locals: Vec<Localization> = Vec::new();
+        let mut errors: Vec<ParseError> = Vec::new();
+        let mut current: Option<Localization> = None;
 
-            let associated_units = &lines[*lang_entry_idx + 1..next_entry_idx];
+        for (i, raw_line) in content.lines().enumerate() {
+            let line_number = i + 1;
+            let line = raw_line.trim();
 
-            units_for_entry.insert(&lines[*lang_entry_idx][2..], associated_units.to_vec());
-        }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
 
-        let mut locals: Vec<Localization> = Vec::new();
-        let localization_unit_regex =
-            regex::Regex::new(r#"(?P<key>.*):(?P<version>\d+)?\s+\"(?P<content>.*)\"$"#).unwrap();
-        for (lang, units) in units_for_entry {
-            let header = lang.replace("l_", "");
-            let mut local_units = Vec::new();
-
-            for unit in units {
-                // unit has the form 'localisation_key:0 "Localisation value"'
-                // important: localisation_key can contain '.' or other special characters
-
-                let unit = unit.trim();
-                if unit.is_empty() || unit.starts_with("#") {
-                    continue;
+            if let Some(lang) = line.strip_prefix("l_") {
+                if let Some(finished) = current.take() {
+                    locals.push(finished);
                 }
+                current = Some(Localization {
+                    lang: lang.trim_end_matches(':').to_string(),
+                    units: Vec::new(),
+                });
+                continue;
+            }
 
-                let caps = localization_unit_regex.captures(unit).unwrap();
-                let key = caps.name("key").unwrap().as_str().to_string();
-                let version = caps.name("version").map(|v| v.as_str().parse().unwrap());
-                let value = caps.name("content").unwrap().as_str().to_string();
-                local_units.push(LocalizationUnit {
-                    key,
-                    version,
-                    value,
+            let Some(localization) = current.as_mut() else {
+                errors.push(ParseError::UnitBeforeLanguageHeader {
+                    line: line_number,
+                    text: raw_line.to_string(),
                 });
+                continue;
+            };
+
+            // unit has the form 'localisation_key:0 "Localisation value"'
+            // important: localisation_key can contain '.' or other special characters
+            match regexes.parse_unit_line(line_number, raw_line, line) {
+                Ok(unit) => localization.units.push(unit),
+                Err(error) => errors.push(error),
             }
+        }
 
-            locals.push(Localization {
-                lang: header,
-                units: local_units,
-            });
+        if let Some(finished) = current.take() {
+            locals.push(finished);
         }
 
-        locals
+        if errors.is_empty() {
+            Ok(locals)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Compiled regexes for matching a single `key:version "value"` unit line, shared between
+/// [`Localization::try_parse`] and [`Localization::try_parse_preserving`] so the two parsing
+/// modes agree on exactly what counts as a malformed or unterminated unit.
+pub(crate) struct UnitLineRegexes {
+    unit: regex::Regex,
+    unterminated: regex::Regex,
+}
+
+impl UnitLineRegexes {
+    pub(crate) fn new() -> Self {
+        Self {
+            unit: regex::Regex::new(r#"^(?P<key>.*):(?P<version>\d+)?\s+"(?P<content>.*)"$"#)
+                .unwrap(),
+            unterminated: regex::Regex::new(r#"^(?P<key>.*):(?P<version>\d+)?\s+"(?P<content>.*)$"#)
+                .unwrap(),
+        }
+    }
+
+    /// Parse a single trimmed unit `line` (with the original, untrimmed `raw_line` kept
+    /// around for error reporting) into a [`LocalizationUnit`].
+    pub(crate) fn parse_unit_line(
+        &self,
+        line_number: usize,
+        raw_line: &str,
+        line: &str,
+    ) -> Result<LocalizationUnit, ParseError> {
+        let Some(caps) = self.unit.captures(line) else {
+            return Err(if self.unterminated.is_match(line) {
+                ParseError::UnterminatedValue {
+                    line: line_number,
+                    text: raw_line.to_string(),
+                }
+            } else {
+                ParseError::MalformedUnit {
+                    line: line_number,
+                    text: raw_line.to_string(),
+                }
+            });
+        };
+
+        let key = caps.name("key").unwrap().as_str().to_string();
+        let version = match caps.name("version") {
+            Some(v) => match v.as_str().parse() {
+                Ok(version) => Some(version),
+                Err(_) => {
+                    return Err(ParseError::InvalidVersion {
+                        line: line_number,
+                        text: raw_line.to_string(),
+                    });
+                }
+            },
+            None => None,
+        };
+        let value = caps.name("content").unwrap().as_str().to_string();
+
+        Ok(LocalizationUnit {
+            key,
+            version,
+            value,
+        })
     }
 }
 
@@ -142,4 +216,68 @@ l_russian:
         assert_eq!(first.units.len(), 21);
         assert_eq!(first.lang, "russian");
     }
+
+    #[test]
+    fn try_parse_reports_unit_before_language_header() {
+        let content = "some_key: \"value\"\nl_english:\n key: \"value\"\n";
+        let errors = Localization::try_parse(content).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![ParseError::UnitBeforeLanguageHeader {
+                line: 1,
+                text: "some_key: \"value\"".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn try_parse_reports_malformed_unit() {
+        let content = "l_english:\n this is not a unit\n";
+        let errors = Localization::try_parse(content).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![ParseError::MalformedUnit {
+                line: 2,
+                text: " this is not a unit".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn try_parse_reports_unterminated_value() {
+        let content = "l_english:\n key: \"unterminated value\n";
+        let errors = Localization::try_parse(content).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![ParseError::UnterminatedValue {
+                line: 2,
+                text: " key: \"unterminated value".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn try_parse_reports_invalid_version() {
+        let content = "l_english:\n key:99999999999999999999 \"value\"\n";
+        let errors = Localization::try_parse(content).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![ParseError::InvalidVersion {
+                line: 2,
+                text: " key:99999999999999999999 \"value\"".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn try_parse_collects_every_error_in_one_pass() {
+        let content = "l_english:\n bad line one\n bad line two\n";
+        let errors = Localization::try_parse(content).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+    }
 }
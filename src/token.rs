@@ -0,0 +1,230 @@
+use crate::LocalizationUnit;
+
+/// A single piece of a localisation value, as produced by [`LocalizationUnit::tokens`].
+///
+/// Paradox localisation values mix plain text with a handful of scripted sigils: bracket
+/// scopes (`[FROM.GetLeader]`), variable references (`$VARIABLE$`), the `\n` newline escape,
+/// colour codes (`§Y...§!`) and icon references (`£icon`). Splitting a value into `Token`s
+/// lets callers validate or transform the scripted parts without disturbing the surrounding
+/// text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<'a> {
+    /// Plain text with no special meaning to the game engine.
+    Literal(&'a str),
+    /// A bracket scope, e.g. `FROM.GetLeader` from `[FROM.GetLeader]`.
+    Scope(String),
+    /// A variable reference, e.g. `VARIABLE` from `$VARIABLE$`.
+    Variable(String),
+    /// A colour code, e.g. `§Y...§!`. `code` is the colour letter (`Y`) and `body` is the
+    /// tokenized text it wraps, since colour codes can themselves contain scopes, variables
+    /// and icons.
+    Color { code: char, body: Vec<Token<'a>> },
+    /// An icon reference, e.g. `icon` from `£icon`.
+    Icon(String),
+    /// The literal `\n` escape sequence.
+    Newline,
+}
+
+impl LocalizationUnit {
+    /// Scan [`LocalizationUnit::value`] and split it into a typed stream of [`Token`]s,
+    /// separating literal text from the scripted parts the game engine treats specially.
+    pub fn tokens(&self) -> Vec<Token<'_>> {
+        tokenize(&self.value)
+    }
+}
+
+fn tokenize(s: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let rest = &s[i..];
+        let ch = rest.chars().next().unwrap();
+
+        if rest.starts_with("\\n") {
+            push_literal(&mut tokens, &s[literal_start..i]);
+            tokens.push(Token::Newline);
+            i += 2;
+            literal_start = i;
+        } else if ch == '[' {
+            match rest[1..].find(']') {
+                Some(offset) => {
+                    let end = i + 1 + offset;
+                    push_literal(&mut tokens, &s[literal_start..i]);
+                    tokens.push(Token::Scope(s[i + 1..end].to_string()));
+                    i = end + 1;
+                    literal_start = i;
+                }
+                None => i += ch.len_utf8(),
+            }
+        } else if ch == '$' {
+            match rest[1..].find('$') {
+                Some(offset) => {
+                    let end = i + 1 + offset;
+                    push_literal(&mut tokens, &s[literal_start..i]);
+                    tokens.push(Token::Variable(s[i + 1..end].to_string()));
+                    i = end + 1;
+                    literal_start = i;
+                }
+                None => i += ch.len_utf8(),
+            }
+        } else if ch == '§' {
+            let mut code_chars = rest[ch.len_utf8()..].chars();
+            match code_chars.next() {
+                Some(code) => {
+                    let body_start = i + ch.len_utf8() + code.len_utf8();
+                    match find_color_close(&s[body_start..]) {
+                        Some(body_end) => {
+                            let body_end = body_start + body_end;
+                            push_literal(&mut tokens, &s[literal_start..i]);
+                            tokens.push(Token::Color {
+                                code,
+                                body: tokenize(&s[body_start..body_end]),
+                            });
+                            i = body_end + '§'.len_utf8() + '!'.len_utf8();
+                            literal_start = i;
+                        }
+                        None => i += ch.len_utf8(),
+                    }
+                }
+                None => i += ch.len_utf8(),
+            }
+        } else if ch == '£' {
+            let icon_start = i + ch.len_utf8();
+            let icon_end = s[icon_start..]
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map_or(s.len(), |offset| icon_start + offset);
+            push_literal(&mut tokens, &s[literal_start..i]);
+            tokens.push(Token::Icon(s[icon_start..icon_end].to_string()));
+            i = icon_end;
+            literal_start = i;
+        } else {
+            i += ch.len_utf8();
+        }
+    }
+
+    push_literal(&mut tokens, &s[literal_start..]);
+    tokens
+}
+
+fn push_literal<'a>(tokens: &mut Vec<Token<'a>>, literal: &'a str) {
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+}
+
+/// Find the byte offset (relative to `s`) of the `§` that closes a colour code opened just
+/// before `s`, accounting for colour codes nested inside the body.
+fn find_color_close(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let rest = &s[i..];
+        let ch = rest.chars().next().unwrap();
+
+        if ch == '§' {
+            let after = i + ch.len_utf8();
+            match s[after..].chars().next() {
+                Some('!') if depth == 0 => return Some(i),
+                Some('!') => {
+                    depth -= 1;
+                    i = after + '!'.len_utf8();
+                }
+                Some(c) => {
+                    depth += 1;
+                    i = after + c.len_utf8();
+                }
+                None => return None,
+            }
+        } else {
+            i += ch.len_utf8();
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(value: &str) -> LocalizationUnit {
+        LocalizationUnit {
+            key: "key".to_string(),
+            version: None,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn tokenizes_scopes_and_variables() {
+        let unit = unit("Hello [FROM.GetLeader], you have $AMOUNT$ troops");
+
+        assert_eq!(
+            unit.tokens(),
+            vec![
+                Token::Literal("Hello "),
+                Token::Scope("FROM.GetLeader".to_string()),
+                Token::Literal(", you have "),
+                Token::Variable("AMOUNT".to_string()),
+                Token::Literal(" troops"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_newline_escapes() {
+        let unit = unit("line one\\nline two");
+
+        assert_eq!(
+            unit.tokens(),
+            vec![
+                Token::Literal("line one"),
+                Token::Newline,
+                Token::Literal("line two"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_icons() {
+        let unit = unit("Press £GFX_icon_button to continue");
+
+        assert_eq!(
+            unit.tokens(),
+            vec![
+                Token::Literal("Press "),
+                Token::Icon("GFX_icon_button".to_string()),
+                Token::Literal(" to continue"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_color_codes_with_nested_tokens() {
+        let unit = unit("§Yimportant [FROM.GetLeader]§! text");
+
+        assert_eq!(
+            unit.tokens(),
+            vec![
+                Token::Color {
+                    code: 'Y',
+                    body: vec![
+                        Token::Literal("important "),
+                        Token::Scope("FROM.GetLeader".to_string()),
+                    ],
+                },
+                Token::Literal(" text"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_sigils_are_kept_as_literal_text() {
+        let unit = unit("price is $5 not a variable");
+
+        assert_eq!(unit.tokens(), vec![Token::Literal("price is $5 not a variable")]);
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{Localization, LocalizationUnit, ParseError, UnitLineRegexes};
+
+/// A single line of a [`LocalizationDocument`], preserving the structure the parser would
+/// otherwise discard.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Line {
+    /// A `#comment` line, without the leading `#`.
+    Comment(String),
+    /// An empty (or whitespace-only) line.
+    Blank,
+    /// A `key:version "value"` unit line.
+    Unit(LocalizationUnit),
+}
+
+/// A parsed `l_<language>` block that keeps its comments and blank lines in their original
+/// positions, so it can be mutated and written back out with the original structure intact.
+///
+/// Built with [`Localization::try_parse_preserving`] and turned back into text with
+/// [`LocalizationDocument::to_string`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalizationDocument {
+    pub lang: String,
+    pub lines: Vec<Line>,
+}
+
+impl LocalizationDocument {
+    /// Re-emit `documents` as Hearts of Iron IV localisation YML text, preserving comments,
+    /// blank lines and unit ordering exactly as they appear in `lines`.
+    pub fn to_string(documents: &[LocalizationDocument]) -> String {
+        let mut out = String::new();
+        for document in documents {
+            out.push_str(&format!("l_{}:\n", document.lang));
+            for line in &document.lines {
+                match line {
+                    Line::Comment(comment) => out.push_str(&format!(" #{comment}\n")),
+                    Line::Blank => out.push('\n'),
+                    Line::Unit(unit) => out.push_str(&format_unit_line(unit)),
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Localization {
+    /// Re-emit `localizations` as Hearts of Iron IV localisation YML text: correct
+    /// `l_<lang>:` headers, one-space indentation, `key:version "value"` formatting, and
+    /// escaping of embedded quotes and newlines in values. Comments and blank lines are not
+    /// preserved; use [`Localization::try_parse_preserving`] and
+    /// [`LocalizationDocument::to_string`] for a round-trip-preserving mode.
+    pub fn to_string(localizations: &[Localization]) -> String {
+        let mut out = String::new();
+        for localization in localizations {
+            out.push_str(&format!("l_{}:\n", localization.lang));
+            for unit in &localization.units {
+                out.push_str(&format_unit_line(unit));
+            }
+        }
+        out
+    }
+
+    /// Like [`Localization::try_parse`], but retains comments and blank lines as
+    /// [`Line::Comment`] and [`Line::Blank`] entries interleaved with the parsed units, so the
+    /// original file structure can be reconstructed with [`LocalizationDocument::to_string`]
+    /// after mutating individual units. Comments and blank lines that appear before the first
+    /// `l_<language>` header are not retained.
+    pub fn try_parse_preserving(
+        content: impl AsRef<str>,
+    ) -> Result<Vec<LocalizationDocument>, Vec<ParseError>> {
+        let content = content.as_ref();
+        let regexes = UnitLineRegexes::new();
+
+        let mut documents: Vec<LocalizationDocument> = Vec::new();
+        let mut errors: Vec<ParseError> = Vec::new();
+        let mut current: Option<LocalizationDocument> = None;
+
+        for (i, raw_line) in content.lines().enumerate() {
+            let line_number = i + 1;
+            let line = raw_line.trim();
+
+            if let Some(lang) = line.strip_prefix("l_") {
+                if let Some(finished) = current.take() {
+                    documents.push(finished);
+                }
+                current = Some(LocalizationDocument {
+                    lang: lang.trim_end_matches(':').to_string(),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+
+            let Some(document) = current.as_mut() else {
+                if !line.is_empty() && !line.starts_with('#') {
+                    errors.push(ParseError::UnitBeforeLanguageHeader {
+                        line: line_number,
+                        text: raw_line.to_string(),
+                    });
+                }
+                continue;
+            };
+
+            if line.is_empty() {
+                document.lines.push(Line::Blank);
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                document.lines.push(Line::Comment(comment.to_string()));
+                continue;
+            }
+
+            match regexes.parse_unit_line(line_number, raw_line, line) {
+                Ok(unit) => document.lines.push(Line::Unit(unit)),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if let Some(finished) = current.take() {
+            documents.push(finished);
+        }
+
+        if errors.is_empty() {
+            Ok(documents)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn format_unit_line(unit: &LocalizationUnit) -> String {
+    let version = unit.version.map(|v| v.to_string()).unwrap_or_default();
+    format!(" {}:{} \"{}\"\n", unit.key, version, escape_value(&unit.value))
+}
+
+fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_re_emits_a_parsed_file() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![
+                LocalizationUnit {
+                    key: "greeting".to_string(),
+                    version: Some(0),
+                    value: "Hello".to_string(),
+                },
+                LocalizationUnit {
+                    key: "farewell".to_string(),
+                    version: None,
+                    value: "Bye".to_string(),
+                },
+            ],
+        }];
+
+        assert_eq!(
+            Localization::to_string(&localizations),
+            "l_english:\n greeting:0 \"Hello\"\n farewell: \"Bye\"\n"
+        );
+    }
+
+    #[test]
+    fn to_string_escapes_embedded_quotes_and_newlines() {
+        let localizations = vec![Localization {
+            lang: "english".to_string(),
+            units: vec![LocalizationUnit {
+                key: "key".to_string(),
+                version: None,
+                value: "He said \"hi\"\nto me".to_string(),
+            }],
+        }];
+
+        assert_eq!(
+            Localization::to_string(&localizations),
+            "l_english:\n key: \"He said \\\"hi\\\"\\nto me\"\n"
+        );
+    }
+
+    #[test]
+    fn preserving_parse_round_trips_comments_and_blank_lines() {
+        let content = "l_english:\n #a comment\n\n greeting:0 \"Hello\"\n";
+
+        let documents = Localization::try_parse_preserving(content).unwrap();
+        assert_eq!(
+            documents,
+            vec![LocalizationDocument {
+                lang: "english".to_string(),
+                lines: vec![
+                    Line::Comment("a comment".to_string()),
+                    Line::Blank,
+                    Line::Unit(LocalizationUnit {
+                        key: "greeting".to_string(),
+                        version: Some(0),
+                        value: "Hello".to_string(),
+                    }),
+                ],
+            }]
+        );
+
+        assert_eq!(
+            LocalizationDocument::to_string(&documents),
+            "l_english:\n #a comment\n\n greeting:0 \"Hello\"\n"
+        );
+    }
+
+    #[test]
+    fn preserving_parse_still_reports_errors() {
+        let content = "l_english:\n this is not a unit\n";
+
+        let errors = Localization::try_parse_preserving(content).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ParseError::MalformedUnit {
+                line: 2,
+                text: " this is not a unit".to_string(),
+            }]
+        );
+    }
+}